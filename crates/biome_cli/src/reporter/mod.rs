@@ -0,0 +1,67 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::Console;
+use std::io;
+
+pub(crate) mod structured;
+pub(crate) mod summary;
+
+use structured::{StructuredReporter, StructuredReporterVisitor};
+use summary::{SummaryReporter, SummaryReporterVisitor};
+
+/// Which [Reporter] to use for a CLI invocation, selected by the
+/// user-facing `--reporter` flag.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum CliReporterKind {
+    /// The decorated, human-facing terminal summary. Default.
+    #[default]
+    Summary,
+    /// Newline-delimited JSON, one record per file; meant for CI bots and
+    /// other tooling that need stable, parseable records instead of having
+    /// to scrape terminal output.
+    Json,
+}
+
+/// The concrete [Reporter] picked for a given [CliReporterKind].
+pub(crate) enum CliReporter {
+    Summary(SummaryReporter),
+    Json(StructuredReporter),
+}
+
+impl Reporter for CliReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        match self {
+            CliReporter::Summary(reporter) => reporter.write(visitor),
+            CliReporter::Json(reporter) => reporter.write(visitor),
+        }
+    }
+}
+
+pub(crate) fn create_reporter(
+    kind: CliReporterKind,
+    execution: Execution,
+    diagnostics_payload: DiagnosticsPayload,
+    summary: TraversalSummary,
+) -> CliReporter {
+    match kind {
+        CliReporterKind::Summary => CliReporter::Summary(SummaryReporter {
+            summary,
+            diagnostics_payload,
+            execution,
+        }),
+        CliReporterKind::Json => CliReporter::Json(StructuredReporter {
+            summary,
+            diagnostics_payload,
+            execution,
+        }),
+    }
+}
+
+pub(crate) fn create_reporter_visitor(
+    kind: CliReporterKind,
+    console: &mut dyn Console,
+) -> Box<dyn ReporterVisitor + '_> {
+    match kind {
+        CliReporterKind::Summary => Box::new(SummaryReporterVisitor(console)),
+        CliReporterKind::Json => Box::new(StructuredReporterVisitor::new(console)),
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::{Resource, Severity};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io;
+
+/// Version of the [StructuredRecord] schema. Bump this whenever a field is
+/// added, removed, renamed, or its meaning changes, so that downstream
+/// parsers (CI bots, editor integrations, other tooling) can detect the
+/// format they're reading instead of guessing from field presence.
+const STRUCTURED_REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// A [Reporter] that emits one newline-delimited JSON record per file,
+/// instead of the decorated terminal tables produced by [super::summary].
+/// This is meant for external agents and CI bots that need stable,
+/// parseable records rather than having to scrape formatted console output.
+pub(crate) struct StructuredReporter {
+    pub(crate) summary: TraversalSummary,
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) execution: Execution,
+}
+
+impl Reporter for StructuredReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        visitor.report_summary(&self.execution, self.summary)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct StructuredReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    files: BTreeMap<String, StructuredFileSummary>,
+}
+
+impl<'a> StructuredReporterVisitor<'a> {
+    pub(crate) fn new(console: &'a mut dyn Console) -> Self {
+        Self {
+            console,
+            files: BTreeMap::new(),
+        }
+    }
+}
+
+impl<'a> ReporterVisitor for StructuredReporterVisitor<'a> {
+    fn report_diagnostics(
+        &mut self,
+        execution: &Execution,
+        diagnostics_payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        for diagnostic in &diagnostics_payload.diagnostics {
+            let location = diagnostic.location().resource.and_then(|r| match r {
+                Resource::File(p) => Some(p),
+                _ => None,
+            });
+            let Some(location) = location else {
+                continue;
+            };
+            let file_summary = self
+                .files
+                .entry(location.into())
+                .or_insert_with(StructuredFileSummary::new);
+
+            if diagnostic.severity() >= diagnostics_payload.diagnostic_level {
+                // Mirror `SummaryReporterVisitor::report_diagnostics`: a verbose-tagged
+                // diagnostic is dropped entirely unless verbose mode is on, so the
+                // structured feed and the terminal summary agree on per-rule counts.
+                if diagnostic.tags().is_verbose() && !diagnostics_payload.verbose {
+                    continue;
+                }
+
+                if diagnostic.severity() > file_summary.severity {
+                    file_summary.severity = diagnostic.severity();
+                }
+
+                let Some(category) = diagnostic.category() else {
+                    continue;
+                };
+
+                if category.name().starts_with("lint/")
+                    && (execution.is_check()
+                        || execution.is_lint()
+                        || (execution.is_ci() && !diagnostic.tags().is_verbose()))
+                {
+                    *file_summary
+                        .categories
+                        .entry(category.name().to_string())
+                        .or_insert(0) += 1;
+                }
+
+                if category.name().starts_with("format")
+                    && (execution.is_check() || execution.is_format() || execution.is_ci())
+                {
+                    file_summary.unformatted = true;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        summary: TraversalSummary,
+    ) -> io::Result<()> {
+        for (file, file_summary) in &self.files {
+            let record = StructuredRecord {
+                schema_version: STRUCTURED_REPORT_SCHEMA_VERSION,
+                file: file.clone(),
+                unformatted: file_summary.unformatted,
+                severity: file_summary.severity,
+                categories: file_summary
+                    .categories
+                    .iter()
+                    .map(|(name, count)| StructuredCategory {
+                        name: name.clone(),
+                        count: *count,
+                    })
+                    .collect(),
+                suggested_fixes_skipped: summary.suggested_fixes_skipped,
+                diagnostics_not_printed: summary.diagnostics_not_printed,
+            };
+            self.console.log(markup! {{record}});
+        }
+
+        Ok(())
+    }
+}
+
+struct StructuredFileSummary {
+    unformatted: bool,
+    severity: Severity,
+    categories: BTreeMap<String, usize>,
+}
+
+impl StructuredFileSummary {
+    fn new() -> Self {
+        Self {
+            unformatted: false,
+            severity: Severity::Hint,
+            categories: BTreeMap::new(),
+        }
+    }
+}
+
+/// One newline-delimited-JSON record describing every diagnostic raised
+/// against a single file. Emitted by [StructuredReporterVisitor].
+#[derive(Debug, Serialize)]
+struct StructuredRecord {
+    schema_version: u32,
+    file: String,
+    unformatted: bool,
+    severity: Severity,
+    categories: Vec<StructuredCategory>,
+    suggested_fixes_skipped: u64,
+    diagnostics_not_printed: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct StructuredCategory {
+    name: String,
+    count: usize,
+}
+
+impl Display for StructuredRecord {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let line = serde_json::to_string(self)
+            .map_err(|error| io::Error::new(io::ErrorKind::Other, error))?;
+        fmt.write_str(&line)?;
+        fmt.write_str("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn structured_record_serializes_one_line_per_file_with_the_documented_schema() {
+        let record = StructuredRecord {
+            schema_version: STRUCTURED_REPORT_SCHEMA_VERSION,
+            file: "a.js".to_string(),
+            unformatted: true,
+            severity: Severity::Error,
+            categories: vec![StructuredCategory {
+                name: "lint/correctness/noUnusedVariables".to_string(),
+                count: 2,
+            }],
+            suggested_fixes_skipped: 1,
+            diagnostics_not_printed: 0,
+        };
+
+        let value: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&record).unwrap()).unwrap();
+
+        assert_eq!(value["schema_version"], STRUCTURED_REPORT_SCHEMA_VERSION);
+        assert_eq!(value["file"], "a.js");
+        assert_eq!(value["unformatted"], true);
+        assert_eq!(value["categories"][0]["name"], "lint/correctness/noUnusedVariables");
+        assert_eq!(value["categories"][0]["count"], 2);
+        assert_eq!(value["suggested_fixes_skipped"], 1);
+        assert_eq!(value["diagnostics_not_printed"], 0);
+    }
+}
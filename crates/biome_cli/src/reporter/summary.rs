@@ -2,7 +2,7 @@ use crate::reporter::terminal::ConsoleTraversalSummary;
 use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
 use biome_console::fmt::{Display, Formatter};
 use biome_console::{markup, Console, ConsoleExt};
-use biome_diagnostics::Resource;
+use biome_diagnostics::{Error as DiagnosticError, PrintDiagnostic, Resource, Severity};
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet};
 use std::io;
@@ -56,6 +56,7 @@ impl<'a> ReporterVisitor for SummaryReporterVisitor<'a> {
         diagnostics_payload: DiagnosticsPayload,
     ) -> io::Result<()> {
         let mut files_to_diagnostics = FileToDiagnostics::default();
+        let mut aggregated_rule_frequency = AggregatedRuleFrequency::default();
 
         for diagnostic in &diagnostics_payload.diagnostics {
             let location = diagnostic.location().resource.and_then(|r| match r {
@@ -74,7 +75,13 @@ impl<'a> ReporterVisitor for SummaryReporterVisitor<'a> {
                         if execution.is_check() || execution.is_lint() {
                             if let Some(category) = category {
                                 if category.name().starts_with("lint/") {
-                                    files_to_diagnostics.insert_lint(location, category.name());
+                                    files_to_diagnostics.insert_lint(
+                                        location,
+                                        diagnostic.severity(),
+                                        category.name(),
+                                    );
+                                    aggregated_rule_frequency
+                                        .insert(category.name(), location);
                                 }
                             }
                         }
@@ -83,10 +90,17 @@ impl<'a> ReporterVisitor for SummaryReporterVisitor<'a> {
                     }
                 }
 
-                if execution.is_check() || execution.is_lint() || execution.is_ci() {
+                if !diagnostic.tags().is_verbose()
+                    && (execution.is_check() || execution.is_lint() || execution.is_ci())
+                {
                     if let Some(category) = category {
                         if category.name().starts_with("lint/") {
-                            files_to_diagnostics.insert_lint(location, category.name());
+                            files_to_diagnostics.insert_lint(
+                                location,
+                                diagnostic.severity(),
+                                category.name(),
+                            );
+                            aggregated_rule_frequency.insert(category.name(), location);
                         }
                     }
                 }
@@ -94,13 +108,17 @@ impl<'a> ReporterVisitor for SummaryReporterVisitor<'a> {
                 if execution.is_check() || execution.is_format() || execution.is_ci() {
                     if let Some(category) = category {
                         if category.name().starts_with("format") {
-                            files_to_diagnostics.insert_format(location);
+                            // Verbose mode is the opt-in for rendering the full unified
+                            // diff of a pending format, rather than a bare one-liner.
+                            let diff_source = diagnostics_payload.verbose.then_some(diagnostic);
+                            files_to_diagnostics.insert_format(location, diff_source);
                         }
                     }
                 }
             }
         }
 
+        self.0.log(markup! {{aggregated_rule_frequency}});
         self.0.log(markup! {{files_to_diagnostics}});
         // self.0.log(markup! {{formats_by_resource}});
         // self.0.log(markup! {{lints_by_category}});
@@ -124,19 +142,121 @@ impl FileToDiagnostics {
         self.0.get_mut(file_name).expect("The file to be tracked")
     }
 
-    fn insert_lint(&mut self, location: &str, rule_name: impl Into<RuleName>) {
+    fn insert_lint(&mut self, location: &str, severity: Severity, rule_name: impl Into<RuleName>) {
         let summary = self.get_summary(location);
-        let rule_name = rule_name.into();
-        if let Some(value) = summary.lints.0.get_mut(&rule_name) {
-            *value += 1;
-        } else {
-            summary.lints.0.insert(rule_name, 1);
-        }
+        summary.lints.insert(severity, rule_name);
     }
 
-    fn insert_format(&mut self, location: &str) {
+    fn insert_format(&mut self, location: &str, diff_source: Option<&DiagnosticError>) {
         let summary = self.get_summary(location);
         summary.formats += 1;
+        if let Some(diagnostic) = diff_source {
+            summary.format_diff.get_or_insert_with(|| diagnostic.clone());
+        }
+    }
+}
+
+/// Per-rule occurrence count across the whole run, together with the set of
+/// distinct files that triggered it at least once.
+#[derive(Debug, Default, Clone)]
+struct RuleOccurrence {
+    count: usize,
+    files: BTreeSet<String>,
+}
+
+/// Project-wide rollup of every `lint/*` rule triggered during the run,
+/// independent of which file(s) triggered it. Unlike [LintsByCategory], which
+/// is scoped to a single file, this is meant to answer "what are the most
+/// frequent offenders across the whole project".
+#[derive(Debug, Default)]
+struct AggregatedRuleFrequency(BTreeMap<&'static str, RuleOccurrence>);
+
+impl AggregatedRuleFrequency {
+    fn insert(&mut self, rule_name: impl Into<RuleName>, file_name: &str) {
+        // Keyed by the rule's code rather than by `RuleName`, whose `Ord` only
+        // compares name lengths and would merge distinct rules of equal length.
+        let RuleName(rule_name) = rule_name.into();
+        let occurrence = self.0.entry(rule_name).or_default();
+        occurrence.count += 1;
+        occurrence.files.insert(file_name.into());
+    }
+}
+
+impl Display for AggregatedRuleFrequency {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+
+        // This table is ranked by occurrence count, so entries are collected
+        // and sorted independently rather than relying on the map's order.
+        let mut entries: Vec<_> = self
+            .0
+            .iter()
+            .map(|(name, occurrence)| (RuleName::from(*name), occurrence))
+            .collect();
+        entries.sort_by(|(left_name, left), (right_name, right)| {
+            right
+                .count
+                .cmp(&left.count)
+                .then_with(|| left_name.0.cmp(right_name.0))
+        });
+
+        let rule_name_str = "Rule Name";
+        let count_str = "Count";
+        let files_str = "Files";
+        let padding = 15usize;
+
+        fmt.write_markup(markup!(
+            <Info>"Top offending rules across the project"</Info>
+        ))?;
+        fmt.write_str("\n\n")?;
+
+        let longest_rule_name = entries
+            .iter()
+            .map(|(name, _)| name.name_len())
+            .max()
+            .unwrap_or(rule_name_str.len());
+        let longest_count_len = entries
+            .iter()
+            .map(|(_, occurrence)| count_digits(occurrence.count))
+            .max()
+            .unwrap_or(count_str.len());
+
+        fmt.write_markup(markup!(
+            {TAB}<Info><Underline>{rule_name_str}</Underline></Info>
+        ))?;
+        fmt.write_markup(markup! {{Padding(longest_rule_name + padding)}})?;
+        fmt.write_markup(markup!(
+            <Info><Dim>{count_str}</Dim></Info>
+        ))?;
+        fmt.write_markup(markup! {{Padding(longest_count_len + padding)}})?;
+        fmt.write_markup(markup!(
+            <Info><Dim>{files_str}</Dim></Info>
+        ))?;
+        fmt.write_str("\n")?;
+
+        for (name, occurrence) in entries {
+            let extra_padding = longest_rule_name.saturating_sub(name.name_len());
+            let count_padding = longest_count_len.saturating_sub(count_digits(occurrence.count));
+            fmt.write_markup(markup! {
+                {TAB}<Emphasis>{name}</Emphasis>
+            })?;
+            fmt.write_markup(markup! {
+                {Padding(extra_padding + padding + rule_name_str.len())}
+            })?;
+            fmt.write_markup(markup! {
+                {occurrence.count}
+            })?;
+            fmt.write_markup(markup! {{Padding(count_padding + padding + count_str.len())}})?;
+            fmt.write_markup(markup! {
+                {occurrence.files.len()}
+            })?;
+            fmt.write_str("\n")?;
+        }
+        fmt.write_str("\n")?;
+
+        Ok(())
     }
 }
 
@@ -162,8 +282,12 @@ impl Display for FileToDiagnostics {
 
 #[derive(Debug, Default)]
 struct SummaryDiagnostics {
-    lints: LintsByCategory,
+    lints: SeverityLints,
     formats: usize,
+    /// Present only in verbose mode: the original format diagnostic, carrying
+    /// the before/after diff advice, kept around so it can be rendered as a
+    /// unified diff instead of the one-line "isn't formatted" notice.
+    format_diff: Option<DiagnosticError>,
 }
 
 impl SummaryDiagnostics {}
@@ -171,9 +295,19 @@ impl SummaryDiagnostics {}
 impl Display for SummaryDiagnostics {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
         if self.formats > 0 {
-            fmt.write_markup(markup! {
-                {TAB}<Info>"The file isn't formatted."</Info>"\n\n"
-            })?;
+            if let Some(diagnostic) = &self.format_diff {
+                fmt.write_markup(markup! {
+                    {TAB}<Info>"The file isn't formatted. "</Info>"\n\n"
+                })?;
+                fmt.write_markup(markup! {
+                    {PrintDiagnostic::simple(diagnostic)}
+                })?;
+                fmt.write_str("\n")?;
+            } else {
+                fmt.write_markup(markup! {
+                    {TAB}<Info>"The file isn't formatted."</Info>"\n\n"
+                })?;
+            }
         }
         fmt.write_markup(markup! {
             {self.lints}
@@ -181,57 +315,131 @@ impl Display for SummaryDiagnostics {
     }
 }
 
+/// Per-file lint tally, split by [Severity] so a file that trips three
+/// errors and twelve warnings shows them under distinct, individually
+/// counted headings rather than one flat table.
 #[derive(Debug, Default)]
-struct LintsByCategory(BTreeMap<RuleName, usize>);
+struct SeverityLints {
+    errors: LintsByCategory,
+    warnings: LintsByCategory,
+    infos: LintsByCategory,
+}
+
+impl SeverityLints {
+    fn insert(&mut self, severity: Severity, rule_name: impl Into<RuleName>) {
+        let bucket = match severity {
+            Severity::Fatal | Severity::Error => &mut self.errors,
+            Severity::Warning => &mut self.warnings,
+            Severity::Information | Severity::Hint => &mut self.infos,
+        };
+        bucket.insert(rule_name);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.errors.is_empty() && self.warnings.is_empty() && self.infos.is_empty()
+    }
+}
 
-impl Display for LintsByCategory {
+impl Display for SeverityLints {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        fmt.write_markup(markup! {
+            {TAB}<Error>{self.errors.total()}" errors"</Error>", "
+            <Warn>{self.warnings.total()}" warnings"</Warn>", "
+            <Info>{self.infos.total()}" info"</Info>"\n\n"
+        })?;
+
+        self.errors.fmt_section(fmt, "Errors")?;
+        self.warnings.fmt_section(fmt, "Warnings")?;
+        self.infos.fmt_section(fmt, "Info")?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+struct LintsByCategory(BTreeMap<RuleName, usize>);
+
+impl LintsByCategory {
+    fn insert(&mut self, rule_name: impl Into<RuleName>) {
+        let rule_name = rule_name.into();
+        if let Some(value) = self.0.get_mut(&rule_name) {
+            *value += 1;
+        } else {
+            self.0.insert(rule_name, 1);
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn total(&self) -> usize {
+        self.0.values().sum()
+    }
+
+    fn fmt_section(&self, fmt: &mut Formatter, title: &str) -> io::Result<()> {
         let rule_name_str = "Rule Name";
+        let code_str = "Code";
         let diagnostics_str = "Diagnostics";
         let padding = 15usize;
 
-        if !self.0.is_empty() {
-            fmt.write_markup(markup!(
-                {TAB}<Info>"Some lint rules were triggered"</Info>
-            ))?;
-            fmt.write_str("\n\n")?;
-            let mut iter = self.0.iter().rev();
-            // SAFETY: it isn't empty
-            let (first_name, first_count) = iter.next().unwrap();
-            let longest_rule_name = first_name.name_len();
-
-            fmt.write_markup(markup!(
-                {TAB}<Info><Underline>{rule_name_str}</Underline></Info>
-            ))?;
-            fmt.write_markup(markup! {{Padding(longest_rule_name + padding)}})?;
-            fmt.write_markup(markup!(
-                <Info><Dim>{diagnostics_str}</Dim></Info>
-            ))?;
-            fmt.write_str("\n")?;
+        if self.0.is_empty() {
+            return Ok(());
+        }
 
-            fmt.write_markup(markup! {
-                {TAB}<Emphasis>{first_name}</Emphasis>{Padding(padding + rule_name_str.len())}{first_count}
-            })?;
+        fmt.write_markup(markup!(
+            {TAB}<Info>{title}</Info>
+        ))?;
+        fmt.write_str("\n\n")?;
 
-            fmt.write_str("\n")?;
+        let longest_rule_name = self
+            .0
+            .keys()
+            .map(|name| name.short_name().len())
+            .max()
+            .unwrap_or(rule_name_str.len());
+        let longest_code = self
+            .0
+            .keys()
+            .map(|name| name.code().len())
+            .max()
+            .unwrap_or(code_str.len());
 
-            for (name, num) in iter {
-                let current_name_len = name.name_len();
-                let extra_padding = longest_rule_name.saturating_sub(current_name_len);
-                fmt.write_markup(markup! {
-                    {TAB}<Emphasis>{name}</Emphasis>
-                })?;
+        fmt.write_markup(markup!(
+            {TAB}<Info><Underline>{rule_name_str}</Underline></Info>
+        ))?;
+        fmt.write_markup(markup! {{Padding(longest_rule_name + padding)}})?;
+        fmt.write_markup(markup!(
+            <Info><Underline>{code_str}</Underline></Info>
+        ))?;
+        fmt.write_markup(markup! {{Padding(longest_code + padding)}})?;
+        fmt.write_markup(markup!(
+            <Info><Dim>{diagnostics_str}</Dim></Info>
+        ))?;
+        fmt.write_str("\n")?;
 
-                fmt.write_markup(markup! {
-                    {Padding(extra_padding + padding + rule_name_str.len())}
-                })?;
+        for (name, num) in self.0.iter().rev() {
+            let name_padding = longest_rule_name.saturating_sub(name.short_name().len());
+            let code_padding = longest_code.saturating_sub(name.code().len());
 
-                fmt.write_markup(markup! {
-                    {num}
-                })?;
-                fmt.write_str("\n")?;
-            }
+            fmt.write_markup(markup! {
+                {TAB}<Emphasis>{name.short_name()}</Emphasis>
+            })?;
+            fmt.write_markup(markup! {{Padding(name_padding + padding + rule_name_str.len())}})?;
+            fmt.write_markup(markup! {
+                <Dim>{name.code()}</Dim>
+            })?;
+            fmt.write_markup(markup! {{Padding(code_padding + padding + code_str.len())}})?;
+            fmt.write_markup(markup! {
+                {num}
+            })?;
+            fmt.write_str("\n")?;
         }
+        fmt.write_str("\n")?;
 
         Ok(())
     }
@@ -250,6 +458,17 @@ impl RuleName {
     fn name_len(&self) -> usize {
         self.0.len()
     }
+
+    /// The diagnostic code, e.g. `lint/correctness/noUnusedVariables`.
+    fn code(&self) -> &str {
+        self.0
+    }
+
+    /// The rule's own name, without its category prefix, e.g.
+    /// `noUnusedVariables`.
+    fn short_name(&self) -> &str {
+        self.0.rsplit('/').next().unwrap_or(self.0)
+    }
 }
 
 impl From<&'static str> for RuleName {
@@ -308,6 +527,13 @@ impl Display for FormatsByFile {
     }
 }
 
+/// Number of decimal digits needed to print `value`, used to size a
+/// right-hand column so it stays aligned with its header regardless of how
+/// wide the printed numbers end up being.
+fn count_digits(value: usize) -> usize {
+    value.to_string().len()
+}
+
 struct Padding(usize);
 impl Display for Padding {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
@@ -327,3 +553,149 @@ impl Display for Tab {
         fmt.write_markup(markup! {{self.0}})
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_console::fmt::Write as FmtWrite;
+    use biome_console::MarkupElements;
+
+    #[derive(Default)]
+    struct TestWrite(String);
+
+    impl FmtWrite for TestWrite {
+        fn write_str(&mut self, _elements: &MarkupElements, content: &str) -> io::Result<()> {
+            self.0.push_str(content);
+            Ok(())
+        }
+    }
+
+    fn render(display: &impl Display) -> String {
+        let mut writer = TestWrite::default();
+        let mut formatter = Formatter::new(&mut writer);
+        display.fmt(&mut formatter).unwrap();
+        writer.0
+    }
+
+    #[test]
+    fn aggregated_rule_frequency_sorts_by_count_descending() {
+        let mut aggregated = AggregatedRuleFrequency::default();
+        aggregated.insert("lint/style/useConst", "a.js");
+        aggregated.insert("lint/correctness/noUnusedVariables", "a.js");
+        aggregated.insert("lint/correctness/noUnusedVariables", "b.js");
+
+        let output = render(&aggregated);
+        let unused_pos = output.find("noUnusedVariables").unwrap();
+        let const_pos = output.find("useConst").unwrap();
+        assert!(
+            unused_pos < const_pos,
+            "the rule with more occurrences should be listed first"
+        );
+    }
+
+    #[test]
+    fn aggregated_rule_frequency_keeps_files_column_aligned_across_count_digit_widths() {
+        // Regression test: the Count column used to be padded by a fixed
+        // width, so a 3-digit count would push the Files column out of
+        // alignment with a 1-digit count's Files column.
+        fn files_column(line: &str) -> usize {
+            let trimmed = line.trim_end();
+            trimmed.rfind(char::is_whitespace).map_or(0, |i| i + 1)
+        }
+
+        let mut aggregated = AggregatedRuleFrequency::default();
+        for i in 0..120 {
+            aggregated.insert("lint/correctness/noUnusedVariables", &format!("f{i}.js"));
+        }
+        aggregated.insert("lint/style/useConst", "a.js");
+
+        let output = render(&aggregated);
+        let header_line = output.lines().find(|line| line.contains("Files")).unwrap();
+        let many_count_line = output
+            .lines()
+            .find(|line| line.contains("noUnusedVariables"))
+            .unwrap();
+        let one_count_line = output
+            .lines()
+            .find(|line| line.contains("useConst"))
+            .unwrap();
+
+        let header_files_col = header_line.find("Files").unwrap();
+        assert_eq!(header_files_col, files_column(many_count_line));
+        assert_eq!(header_files_col, files_column(one_count_line));
+    }
+
+    #[test]
+    fn lints_by_category_keeps_header_and_body_columns_aligned_for_short_names() {
+        // Regression test: a rule name shorter than the "Rule Name" header
+        // used to make the header wider than the body, drifting the "Code"
+        // column header out of alignment with the code it labels.
+        let mut lints = LintsByCategory::default();
+        lints.insert("lint/a11y/eq");
+
+        let mut writer = TestWrite::default();
+        let mut formatter = Formatter::new(&mut writer);
+        lints.fmt_section(&mut formatter, "Errors").unwrap();
+        let output = writer.0;
+
+        let header_line = output.lines().find(|line| line.contains("Code")).unwrap();
+        let body_line = output.lines().find(|line| line.contains("lint/a11y/eq")).unwrap();
+        let header_code_col = header_line.find("Code").unwrap();
+        let body_code_col = body_line.find("lint/a11y/eq").unwrap();
+        assert_eq!(
+            header_code_col, body_code_col,
+            "the Code column should line up under its header"
+        );
+    }
+
+    #[test]
+    fn severity_lints_buckets_by_severity() {
+        let mut severity_lints = SeverityLints::default();
+        severity_lints.insert(Severity::Error, "lint/correctness/noUnusedVariables");
+        severity_lints.insert(Severity::Warning, "lint/style/useConst");
+        severity_lints.insert(Severity::Information, "lint/nursery/noUselessFragments");
+
+        assert_eq!(severity_lints.errors.total(), 1);
+        assert_eq!(severity_lints.warnings.total(), 1);
+        assert_eq!(severity_lints.infos.total(), 1);
+    }
+
+    #[test]
+    fn rule_name_splits_code_from_short_name() {
+        let rule_name = RuleName::from("lint/correctness/noUnusedVariables");
+        assert_eq!(rule_name.code(), "lint/correctness/noUnusedVariables");
+        assert_eq!(rule_name.short_name(), "noUnusedVariables");
+    }
+
+    #[test]
+    fn insert_format_keeps_format_diff_none_outside_verbose_mode() {
+        let mut files_to_diagnostics = FileToDiagnostics::default();
+        files_to_diagnostics.track_file("a.js");
+        files_to_diagnostics.insert_format("a.js", None);
+
+        let summary = files_to_diagnostics.get_summary("a.js");
+        assert!(summary.format_diff.is_none());
+
+        let output = render(&*summary);
+        assert!(output.contains("The file isn't formatted."));
+    }
+
+    #[test]
+    fn insert_format_captures_the_diagnostic_for_a_diff_in_verbose_mode() {
+        let diagnostic: DiagnosticError =
+            std::io::Error::new(std::io::ErrorKind::Other, "a.js would be reformatted").into();
+
+        let mut files_to_diagnostics = FileToDiagnostics::default();
+        files_to_diagnostics.track_file("a.js");
+        files_to_diagnostics.insert_format("a.js", Some(&diagnostic));
+
+        let summary = files_to_diagnostics.get_summary("a.js");
+        assert!(summary.format_diff.is_some());
+
+        let output = render(&*summary);
+        assert!(
+            output.contains("a.js would be reformatted"),
+            "verbose mode should render the captured diagnostic, not just the one-line notice"
+        );
+    }
+}